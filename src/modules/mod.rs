@@ -0,0 +1,3 @@
+/// The `path` module contains the `Path` type used to describe the location
+/// of a value within the input during deserialization.
+pub mod path;
@@ -0,0 +1,256 @@
+use std::fmt::{self, Display};
+
+/// Path to the current value in the input, like `dependencies.serde.typo1`.
+///
+/// The path is represented as a singly linked list that borrows each node from
+/// its parent on the stack while a value is being deserialized. `Root` marks
+/// the start of the document and every other variant points back to the
+/// `parent` node it was reached from, so a path is always walked from the leaf
+/// towards the root.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Path<'a> {
+    /// The root of the document.
+    Root,
+    /// An element of a sequence, identified by its zero-based `index`.
+    Seq {
+        /// The node this element was reached from.
+        parent: &'a Path<'a>,
+        /// The zero-based index of the element within the sequence.
+        index: usize,
+    },
+    /// An entry of a mapping, identified by its `key`.
+    Map {
+        /// The node this entry was reached from.
+        parent: &'a Path<'a>,
+        /// The key of the entry within the mapping.
+        key: &'a str,
+    },
+    /// An aliased value reached through a YAML anchor.
+    Alias {
+        /// The node this alias was reached from.
+        parent: &'a Path<'a>,
+    },
+    /// A value whose location could not be determined.
+    Unknown {
+        /// The node this value was reached from.
+        parent: &'a Path<'a>,
+    },
+}
+
+impl<'a> Display for Path<'a> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Parent<'a>(&'a Path<'a>);
+
+        impl<'a> Display for Parent<'a> {
+            fn fmt(
+                &self,
+                formatter: &mut fmt::Formatter<'_>,
+            ) -> fmt::Result {
+                match self.0 {
+                    Path::Root => Ok(()),
+                    path => write!(formatter, "{}.", path),
+                }
+            }
+        }
+
+        match self {
+            Path::Root => formatter.write_str("."),
+            Path::Seq { parent, index } => {
+                write!(formatter, "{}\\[{}\\]", Parent(parent), index)
+            }
+            Path::Map { parent, key } => {
+                write!(formatter, "{}{}", Parent(parent), key)
+            }
+            Path::Alias { parent } => {
+                write!(formatter, "{}", Parent(parent))
+            }
+            Path::Unknown { parent } => {
+                write!(formatter, "{}?", Parent(parent))
+            }
+        }
+    }
+}
+
+/// A single path segment, detached from the borrowed parent chain.
+///
+/// Where [`Path`] borrows each node from its parent on the stack, `Segment`
+/// owns its data, so a `Vec<Segment>` can outlive the deserialization and be
+/// inspected programmatically instead of parsing the [`Display`] string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Segment {
+    /// An element of a sequence, identified by its zero-based index.
+    Index(usize),
+    /// An entry of a mapping, identified by its key.
+    Key(String),
+    /// An aliased value reached through a YAML anchor.
+    Alias,
+    /// A value whose location could not be determined.
+    Unknown,
+}
+
+impl<'a> Path<'a> {
+    /// Renders the path as an [RFC 6901] JSON Pointer.
+    ///
+    /// Unlike the [`Display`] representation, which is tuned for human-readable
+    /// error messages, the pointer is a machine-readable string that tools can
+    /// use to map a deserialization error back to the exact offending node
+    /// (for example to power IDE/LSP-style "jump to offending key" features).
+    ///
+    /// The pointer starts as an empty string and gains one `/`-prefixed
+    /// reference token per segment, walking from the root down to this leaf:
+    ///
+    /// * a [`Path::Seq`] contributes its index rendered as decimal,
+    /// * a [`Path::Map`] contributes its key with `~` escaped to `~0` and `/`
+    ///   escaped to `~1`,
+    /// * a [`Path::Alias`] contributes an empty token and a [`Path::Unknown`]
+    ///   contributes the `?` sentinel.
+    ///
+    /// Because each node only borrows a reference to its `parent`, the chain is
+    /// first collected leaf-to-root and then reversed before the tokens are
+    /// emitted.
+    ///
+    /// The pointer is rendered only from a `Path`; no `Error` accessor returns
+    /// it alongside the error message.
+    ///
+    /// [RFC 6901]: https://datatracker.ietf.org/doc/html/rfc6901
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_yml::modules::path::Path;
+    ///
+    /// let root = Path::Root;
+    /// let seq = Path::Seq { parent: &root, index: 1 };
+    /// let map = Path::Map { parent: &seq, key: "first" };
+    /// assert_eq!(map.to_json_pointer(), "/1/first");
+    /// ```
+    pub fn to_json_pointer(&self) -> String {
+        // Collect the chain from this leaf up to (but excluding) the root.
+        let mut chain: Vec<&Path<'_>> = Vec::new();
+        let mut current = self;
+        loop {
+            match current {
+                Path::Root => break,
+                Path::Seq { parent, .. }
+                | Path::Map { parent, .. }
+                | Path::Alias { parent }
+                | Path::Unknown { parent } => {
+                    chain.push(current);
+                    current = parent;
+                }
+            }
+        }
+
+        // The chain was gathered leaf-to-root, so reverse it before emitting.
+        chain.reverse();
+
+        let mut pointer = String::new();
+        for segment in chain {
+            pointer.push('/');
+            match segment {
+                Path::Seq { index, .. } => {
+                    pointer.push_str(&index.to_string());
+                }
+                Path::Map { key, .. } => pointer.push_str(
+                    &key.replace('~', "~0").replace('/', "~1"),
+                ),
+                Path::Alias { .. } => {}
+                Path::Unknown { .. } => pointer.push('?'),
+                Path::Root => {}
+            }
+        }
+        pointer
+    }
+
+    /// Returns an iterator over this node and all of its ancestors.
+    ///
+    /// Iteration starts at `self` and follows the `parent` links up to and
+    /// including [`Path::Root`], mirroring the ancestor-aware traversal used by
+    /// tree path maps. This lets consumers inspect an error location without
+    /// string-matching the escaped `\[n\]` [`Display`] syntax.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_yml::modules::path::Path;
+    ///
+    /// let root = Path::Root;
+    /// let seq = Path::Seq { parent: &root, index: 0 };
+    /// let map = Path::Map { parent: &seq, key: "key" };
+    /// assert_eq!(map.ancestors().count(), 3);
+    /// ```
+    pub fn ancestors(&self) -> impl Iterator<Item = &Path<'a>> {
+        let mut next = Some(self);
+        std::iter::from_fn(move || {
+            let current = next?;
+            next = match current {
+                Path::Root => None,
+                Path::Seq { parent, .. }
+                | Path::Map { parent, .. }
+                | Path::Alias { parent }
+                | Path::Unknown { parent } => Some(parent),
+            };
+            Some(current)
+        })
+    }
+
+    /// Returns the depth of this node, i.e. the number of segments between the
+    /// root and this leaf.
+    ///
+    /// [`Path::Root`] has a depth of `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_yml::modules::path::Path;
+    ///
+    /// let root = Path::Root;
+    /// let seq = Path::Seq { parent: &root, index: 0 };
+    /// assert_eq!(root.depth(), 0);
+    /// assert_eq!(seq.depth(), 1);
+    /// ```
+    pub fn depth(&self) -> usize {
+        // Every ancestor but the root contributes one segment.
+        self.ancestors().count() - 1
+    }
+
+    /// Collects the path into an owned list of [`Segment`]s ordered
+    /// root-to-leaf.
+    ///
+    /// The [`Path::Root`] node is not represented; an empty vector therefore
+    /// denotes the root itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_yml::modules::path::{Path, Segment};
+    ///
+    /// let root = Path::Root;
+    /// let seq = Path::Seq { parent: &root, index: 0 };
+    /// let map = Path::Map { parent: &seq, key: "key" };
+    /// assert_eq!(
+    ///     map.segments(),
+    ///     vec![Segment::Index(0), Segment::Key("key".to_string())],
+    /// );
+    /// ```
+    pub fn segments(&self) -> Vec<Segment> {
+        let mut segments: Vec<Segment> = self
+            .ancestors()
+            .filter_map(|path| match path {
+                Path::Root => None,
+                Path::Seq { index, .. } => {
+                    Some(Segment::Index(*index))
+                }
+                Path::Map { key, .. } => {
+                    Some(Segment::Key((*key).to_string()))
+                }
+                Path::Alias { .. } => Some(Segment::Alias),
+                Path::Unknown { .. } => Some(Segment::Unknown),
+            })
+            .collect();
+
+        // `ancestors` yields leaf-to-root, so reverse for root-to-leaf order.
+        segments.reverse();
+        segments
+    }
+}
@@ -1,9 +1,130 @@
 use std::{
     error::Error,
+    fmt,
     fs::{self},
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
 };
 
+/// An absolute path that is guaranteed to point at an existing directory.
+///
+/// `AbsDir` can only be built through [`AbsDir::new`], which canonicalizes the
+/// path (creating the directory first when it does not yet exist). Holding an
+/// `AbsDir` is therefore a compile-time signal that a path has already been
+/// checked to exist, to be a directory, and to be resolved against the real
+/// filesystem rather than an unpredictable current working directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AbsDir(PathBuf);
+
+/// An error produced while constructing an [`AbsDir`].
+#[derive(Debug)]
+pub enum AbsDirError {
+    /// The target exists but is not a directory.
+    NotADirectory(PathBuf),
+    /// The path contains a `..` traversal component.
+    Traversal(PathBuf),
+    /// The directory could not be created or canonicalized.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for AbsDirError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AbsDirError::NotADirectory(path) => write!(
+                formatter,
+                "❌ Error: {} is not a directory.",
+                path.display()
+            ),
+            AbsDirError::Traversal(path) => write!(
+                formatter,
+                "❌ Error: {} must not contain `..` components.",
+                path.display()
+            ),
+            AbsDirError::Io(error) => write!(formatter, "{}", error),
+        }
+    }
+}
+
+impl Error for AbsDirError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AbsDirError::NotADirectory(_)
+            | AbsDirError::Traversal(_) => None,
+            AbsDirError::Io(error) => Some(error),
+        }
+    }
+}
+
+impl AbsDir {
+    /// Constructs an `AbsDir`, creating the directory when necessary.
+    ///
+    /// If `path` already exists it must be a directory, otherwise
+    /// [`AbsDirError::NotADirectory`] is returned. A path containing a `..`
+    /// traversal component is rejected with [`AbsDirError::Traversal`]. The
+    /// path is then canonicalized to an absolute location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde_yml::utilities::directory::AbsDir;
+    ///
+    /// let dir = AbsDir::new("logs").expect("logs is a directory");
+    /// assert!(dir.as_path().is_absolute());
+    /// ```
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, AbsDirError> {
+        let path = path.as_ref();
+
+        // Reject traversal up front so `canonicalize` can never resolve the
+        // path outside the tree the caller intended.
+        if path
+            .components()
+            .any(|component| component == Component::ParentDir)
+        {
+            return Err(AbsDirError::Traversal(path.to_path_buf()));
+        }
+
+        if path.exists() {
+            if !path.is_dir() {
+                return Err(AbsDirError::NotADirectory(
+                    path.to_path_buf(),
+                ));
+            }
+        } else {
+            fs::create_dir_all(path).map_err(AbsDirError::Io)?;
+        }
+
+        let canonical =
+            fs::canonicalize(path).map_err(AbsDirError::Io)?;
+        Ok(AbsDir(canonical))
+    }
+
+    /// Returns the canonical, absolute path of this directory.
+    pub fn as_path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Joins a validated child component onto this directory.
+    ///
+    /// The child is sanitized with [`sanitize_child_name`] and the result is
+    /// verified to remain inside this directory, so it cannot escape via `..`
+    /// or an absolute component.
+    pub fn join_child(&self, child: &str) -> Result<PathBuf, String> {
+        let child = sanitize_child_name(child)?;
+        let candidate = self.0.join(&child);
+
+        // Defensive only: `sanitize_child_name` already guarantees `child` is a
+        // single component with no separators or `..`, so it can never escape
+        // this directory. The check is kept to make that invariant explicit.
+        if !candidate.starts_with(&self.0) {
+            return Err(format!(
+                "❌ Error: {:?} would escape the output directory.",
+                child
+            ));
+        }
+
+        Ok(candidate)
+    }
+}
+
 /// Ensures a directory exists, creating it if necessary.
 ///
 /// This function takes a reference to a `Path` object for a directory and a
@@ -33,28 +154,135 @@ use std::{
 ///     Err(e) => eprintln!("{}", e),
 /// }
 /// ```
-pub fn directory(dir: &Path, name: &str) -> Result<(), String> {
-    if dir.exists() {
-        if !dir.is_dir() {
-            return Err(format!(
-                "❌ Error: {} is not a directory.",
-                name
-            ));
-        }
-    } else {
-        match fs::create_dir_all(dir) {
-            Ok(_) => {}
-            Err(e) => {
-                return Err(format!(
-                    "❌ Error: Cannot create {} directory: {}",
-                    name, e
-                ))
-            }
-        }
+pub fn directory(
+    dir: impl AsRef<Path>,
+    name: &str,
+) -> Result<(), String> {
+    let dir = dir.as_ref();
+
+    // Validate the leaf component (rejecting separators and `.`/`..`). When
+    // the leaf is valid, `canonical_output_dir` also creates the parent
+    // directory if it is missing.
+    if let (Some(parent), Some(child)) =
+        (dir.parent(), dir.file_name())
+    {
+        let base = if parent.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            parent
+        };
+        canonical_output_dir(base, &child.to_string_lossy())?;
     }
+
+    // Resolving through `AbsDir` creates the directory when missing, rejects
+    // `..` traversal anywhere in the path, and fails when the target exists
+    // but is not a directory.
+    AbsDir::new(dir).map_err(|e| {
+        format!("❌ Error: Cannot use {} directory: {}", name, e)
+    })?;
     Ok(())
 }
 
+/// Sanitizes a single path component so it cannot escape its intended parent.
+///
+/// Spaces are replaced with underscores (preserving the previous behaviour of
+/// [`move_output_directory`]) and the result is rejected if it is empty, a `.`
+/// or `..` traversal component, or contains a path separator. Control and
+/// reserved characters (`<>:"|?*` and any control character) are stripped.
+///
+/// # Arguments
+///
+/// * `name` - The untrusted component, e.g. a site name.
+///
+/// # Returns
+///
+/// * `Result<String, String>` - The sanitized component, or an error message
+///   describing why the name was rejected.
+pub fn sanitize_child_name(name: &str) -> Result<String, String> {
+    let name = name.replace(' ', "_");
+
+    if name.is_empty() || name == "." || name == ".." {
+        return Err(format!(
+            "❌ Error: {:?} is not a valid directory name.",
+            name
+        ));
+    }
+
+    if name.contains('/') || name.contains('\\') {
+        return Err(format!(
+            "❌ Error: {:?} must not contain path separators.",
+            name
+        ));
+    }
+
+    let sanitized: String = name
+        .chars()
+        .filter(|c| {
+            !c.is_control()
+                && !matches!(
+                    c,
+                    '<' | '>' | ':' | '"' | '|' | '?' | '*'
+                )
+        })
+        .collect();
+
+    if sanitized.is_empty()
+        || sanitized == "."
+        || sanitized == ".."
+    {
+        return Err(format!(
+            "❌ Error: {:?} is not a valid directory name after sanitization.",
+            name
+        ));
+    }
+
+    Ok(sanitized)
+}
+
+/// Resolves `child` inside `base` and verifies the result stays within `base`.
+///
+/// `base` is created if necessary and canonicalized to an absolute path, then
+/// the sanitized `child` is joined onto it. The returned path is guaranteed to
+/// live inside `base`, so callers can safely `fs::rename`/`fs::create_dir_all`
+/// into it without the risk of clobbering files outside the target tree.
+///
+/// # Arguments
+///
+/// * `base` - The directory the output must stay within.
+/// * `child` - The untrusted child component to append.
+///
+/// # Returns
+///
+/// * `Result<PathBuf, String>` - The validated, absolute output path, or an
+///   error message.
+pub fn canonical_output_dir(
+    base: &Path,
+    child: &str,
+) -> Result<PathBuf, String> {
+    let child = sanitize_child_name(child)?;
+
+    // The base must exist before it can be canonicalized.
+    fs::create_dir_all(base).map_err(|e| {
+        format!("❌ Error: Cannot create base directory: {}", e)
+    })?;
+
+    let base = fs::canonicalize(base).map_err(|e| {
+        format!("❌ Error: Cannot resolve base directory: {}", e)
+    })?;
+
+    let candidate = base.join(&child);
+
+    // The sanitized child cannot traverse upwards, but verify defensively.
+    if !candidate.starts_with(&base) {
+        return Err(format!(
+            "❌ Error: {:?} would escape the output directory.",
+            child
+        ));
+    }
+
+    Ok(candidate)
+}
+
 /// Moves the output directory to the public directory.
 ///
 /// This function takes a reference to a `Path` object for the output directory
@@ -100,8 +328,18 @@ pub fn move_output_directory(
 
     fs::create_dir(public_dir)?;
 
-    let site_name = site_name.replace(' ', "_");
-    let new_project_dir = public_dir.join(site_name);
+    // Resolve `public/` to an `AbsDir` and join the validated site name onto
+    // it so the output cannot escape `public/`.
+    let public_dir = AbsDir::new(public_dir).map_err(|e| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            e.to_string(),
+        )
+    })?;
+    let new_project_dir =
+        public_dir.join_child(site_name).map_err(|e| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, e)
+        })?;
     fs::create_dir_all(&new_project_dir)?;
 
     fs::rename(out_dir, &new_project_dir)?;
@@ -139,17 +377,20 @@ pub fn move_output_directory(
 ///     Err(e) => eprintln!("Error cleaning up directories: {}", e),
 /// }
 /// ```
-pub fn cleanup_directory(
-    directories: &[&Path],
+pub fn cleanup_directory<P: AsRef<Path>>(
+    directories: &[P],
 ) -> Result<(), Box<dyn Error>> {
     for directory in directories {
+        let directory = directory.as_ref();
         if !directory.exists() {
             continue;
         }
 
         println!("\n❯ Cleaning up directories");
 
-        fs::remove_dir_all(directory)?;
+        // Resolve to an `AbsDir` first so we never remove a non-directory.
+        let directory = AbsDir::new(directory)?;
+        fs::remove_dir_all(directory.as_path())?;
 
         println!("  Done.\n");
     }
@@ -185,15 +426,37 @@ pub fn cleanup_directory(
 ///     Err(e) => eprintln!("Error creating directories: {}", e),
 /// }
 /// ```
-pub fn create_directory(
-    directories: &[&Path],
+pub fn create_directory<P: AsRef<Path>>(
+    directories: &[P],
 ) -> Result<(), Box<dyn Error>> {
     for directory in directories {
+        let directory = directory.as_ref();
         if directory.exists() {
             continue;
         }
 
-        fs::create_dir(directory)?;
+        // Validate the leaf component; when it is valid, the parent directory
+        // is created if it is missing.
+        if let (Some(parent), Some(child)) =
+            (directory.parent(), directory.file_name())
+        {
+            let base = if parent.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                parent
+            };
+            canonical_output_dir(base, &child.to_string_lossy())
+                .map_err(|e| {
+                    Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        e,
+                    )) as Box<dyn Error>
+                })?;
+        }
+
+        // Constructing the `AbsDir` creates and canonicalizes the directory
+        // and rejects `..` traversal anywhere in the path.
+        AbsDir::new(directory)?;
     }
 
     Ok(())
@@ -258,3 +521,94 @@ pub fn truncate(path: &Path, length: usize) -> Option<String> {
         None
     }
 }
+
+/// Options controlling how [`truncate_with`] renders a truncated path.
+///
+/// These let callers produce portable or scripted output that does not depend
+/// on the platform separators baked into `PathBuf`.
+#[derive(Clone, Debug, Default)]
+pub struct TruncateOptions {
+    /// The separator used to join the kept components. When `None`, the
+    /// platform separator ([`std::path::MAIN_SEPARATOR`]) is used.
+    pub separator: Option<String>,
+    /// Whether to append the separator to the output when the final component
+    /// refers to a directory, so the tail reads `report/2023/05/` rather than
+    /// `report/2023/05`.
+    pub trailing_separator: bool,
+}
+
+/// Truncates a path like [`truncate`], honouring the supplied [`TruncateOptions`].
+///
+/// Unlike [`truncate`], the kept components are joined with the configured
+/// separator instead of the OS default, and — when `trailing_separator` is set
+/// and `path` is a directory — the separator is appended to the result.
+///
+/// # Arguments
+///
+/// * `path` - The path to truncate.
+/// * `length` - The number of path components to keep.
+/// * `opts` - The formatting options to apply.
+///
+/// # Returns
+///
+/// * An `Option` of the last `length` components as a string. `None` is returned when `length` is `0` or the path has fewer than `length` components; a path with exactly `length` components is returned whole.
+///
+/// # Example
+///
+/// ```
+/// use serde_yml::utilities::directory::{truncate_with, TruncateOptions};
+/// use std::path::Path;
+///
+/// let long_path = Path::new("home/user/documents/report/2023/05");
+///
+/// let opts = TruncateOptions {
+///     separator: Some("/".to_string()),
+///     trailing_separator: false,
+/// };
+///
+/// if let Some(truncated) = truncate_with(long_path, 3, &opts) {
+///     assert_eq!(truncated, "report/2023/05");
+/// }
+/// ```
+pub fn truncate_with(
+    path: &Path,
+    length: usize,
+    opts: &TruncateOptions,
+) -> Option<String> {
+    // Checks if the length is 0. If it is, returns `None`.
+    if length == 0 {
+        return None;
+    }
+
+    // Collects the last `length` components in reverse, then restores order.
+    let mut components: Vec<String> = path
+        .components()
+        .rev()
+        .take(length)
+        .map(|component| {
+            component.as_os_str().to_string_lossy().to_string()
+        })
+        .collect();
+
+    // If there were fewer than `length` components, the path was not truncated.
+    if components.len() != length {
+        return None;
+    }
+
+    components.reverse();
+
+    // Determines the separator, falling back to the platform default.
+    let separator = match &opts.separator {
+        Some(separator) => separator.clone(),
+        None => std::path::MAIN_SEPARATOR.to_string(),
+    };
+
+    let mut truncated = components.join(&separator);
+
+    // Marks directories with a trailing separator when requested.
+    if opts.trailing_separator && path.is_dir() {
+        truncated.push_str(&separator);
+    }
+
+    Some(truncated)
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use serde_yml::modules::path::Path;
+    use serde_yml::modules::path::{Path, Segment};
 
     /// Test the Path::Root variant.
     ///
@@ -301,4 +301,185 @@ mod tests {
         };
         assert_eq!(format!("{}", unknown), "parent_key.?");
     }
+
+    /// Test the JSON Pointer rendering of the root path.
+    ///
+    /// This test ensures that the root on its own maps to the empty pointer.
+    #[test]
+    fn test_json_pointer_root() {
+        let path = Path::Root;
+        assert_eq!(path.to_json_pointer(), "");
+    }
+
+    /// Test the JSON Pointer rendering of a sequence path.
+    ///
+    /// This test checks that a sequence index is emitted as a decimal token.
+    #[test]
+    fn test_json_pointer_seq() {
+        let root = Path::Root;
+        let path = Path::Seq {
+            parent: &root,
+            index: 42,
+        };
+        assert_eq!(path.to_json_pointer(), "/42");
+    }
+
+    /// Test the JSON Pointer rendering of a map path.
+    ///
+    /// This test checks that a map key is emitted verbatim when it needs no
+    /// escaping.
+    #[test]
+    fn test_json_pointer_map() {
+        let root = Path::Root;
+        let path = Path::Map {
+            parent: &root,
+            key: "key",
+        };
+        assert_eq!(path.to_json_pointer(), "/key");
+    }
+
+    /// Test JSON Pointer escaping of the `~` and `/` characters in map keys.
+    ///
+    /// This test validates that keys are escaped per RFC 6901, with `~`
+    /// becoming `~0` and `/` becoming `~1`.
+    #[test]
+    fn test_json_pointer_map_escaping() {
+        let root = Path::Root;
+        let path = Path::Map {
+            parent: &root,
+            key: "a/b~c",
+        };
+        assert_eq!(path.to_json_pointer(), "/a~1b~0c");
+    }
+
+    /// Test the JSON Pointer sentinels for the `Alias` and `Unknown` variants.
+    ///
+    /// This test ensures that an alias contributes an empty token and an
+    /// unknown contributes the `?` sentinel.
+    #[test]
+    fn test_json_pointer_alias_and_unknown() {
+        let root = Path::Root;
+        let alias = Path::Alias { parent: &root };
+        assert_eq!(alias.to_json_pointer(), "/");
+
+        let unknown = Path::Unknown { parent: &root };
+        assert_eq!(unknown.to_json_pointer(), "/?");
+    }
+
+    /// Test the JSON Pointer rendering of a deeply nested path.
+    ///
+    /// This test checks that tokens are emitted root-to-leaf across a mix of
+    /// sequences, maps, aliases and unknowns.
+    #[test]
+    fn test_json_pointer_deeply_nested() {
+        let root = Path::Root;
+        let seq1 = Path::Seq {
+            parent: &root,
+            index: 1,
+        };
+        let map1 = Path::Map {
+            parent: &seq1,
+            key: "first",
+        };
+        let seq2 = Path::Seq {
+            parent: &map1,
+            index: 2,
+        };
+        let map2 = Path::Map {
+            parent: &seq2,
+            key: "second",
+        };
+        let alias = Path::Alias { parent: &map2 };
+        let unknown = Path::Unknown { parent: &alias };
+        assert_eq!(unknown.to_json_pointer(), "/1/first/2/second//?");
+    }
+
+    /// Test that `ancestors` yields the node and its parents up to the root.
+    ///
+    /// This test checks the leaf-to-root ordering and that the root is
+    /// included.
+    #[test]
+    fn test_ancestors() {
+        let root = Path::Root;
+        let seq = Path::Seq {
+            parent: &root,
+            index: 0,
+        };
+        let map = Path::Map {
+            parent: &seq,
+            key: "key",
+        };
+
+        let ancestors: Vec<Path> = map.ancestors().copied().collect();
+        assert_eq!(ancestors, vec![map, seq, root]);
+    }
+
+    /// Test that `ancestors` on the root yields only the root.
+    ///
+    /// This test ensures the iterator terminates at the root.
+    #[test]
+    fn test_ancestors_root_only() {
+        let root = Path::Root;
+        let ancestors: Vec<Path> = root.ancestors().copied().collect();
+        assert_eq!(ancestors, vec![Path::Root]);
+    }
+
+    /// Test the `depth` of various paths.
+    ///
+    /// This test validates that the root has depth `0` and each additional
+    /// segment increases the depth by one.
+    #[test]
+    fn test_depth() {
+        let root = Path::Root;
+        assert_eq!(root.depth(), 0);
+
+        let seq = Path::Seq {
+            parent: &root,
+            index: 0,
+        };
+        assert_eq!(seq.depth(), 1);
+
+        let map = Path::Map {
+            parent: &seq,
+            key: "key",
+        };
+        assert_eq!(map.depth(), 2);
+    }
+
+    /// Test that `segments` returns an owned list ordered root-to-leaf.
+    ///
+    /// This test checks that every variant maps to the matching `Segment`.
+    #[test]
+    fn test_segments() {
+        let root = Path::Root;
+        let seq = Path::Seq {
+            parent: &root,
+            index: 1,
+        };
+        let map = Path::Map {
+            parent: &seq,
+            key: "key",
+        };
+        let alias = Path::Alias { parent: &map };
+        let unknown = Path::Unknown { parent: &alias };
+
+        assert_eq!(
+            unknown.segments(),
+            vec![
+                Segment::Index(1),
+                Segment::Key("key".to_string()),
+                Segment::Alias,
+                Segment::Unknown,
+            ]
+        );
+    }
+
+    /// Test that `segments` on the root is empty.
+    ///
+    /// This test ensures the root contributes no segment.
+    #[test]
+    fn test_segments_root_empty() {
+        let root = Path::Root;
+        assert!(root.segments().is_empty());
+    }
 }
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    use serde_yml::utilities::directory::{
+        canonical_output_dir, sanitize_child_name, truncate_with,
+        AbsDir, TruncateOptions,
+    };
+    use std::path::Path;
+
+    /// Test that `truncate_with` joins the kept components with the configured
+    /// separator.
+    ///
+    /// This test checks that the override separator is used instead of the OS
+    /// default.
+    #[test]
+    fn test_truncate_with_custom_separator() {
+        let path =
+            Path::new("home/user/documents/report/2023/05");
+        let opts = TruncateOptions {
+            separator: Some("/".to_string()),
+            trailing_separator: false,
+        };
+        assert_eq!(
+            truncate_with(path, 3, &opts),
+            Some("report/2023/05".to_string())
+        );
+    }
+
+    /// Test that `truncate_with` returns `None` when the length is zero.
+    ///
+    /// This test mirrors the behaviour of `truncate`.
+    #[test]
+    fn test_truncate_with_zero_length() {
+        let path = Path::new("a/b/c");
+        let opts = TruncateOptions::default();
+        assert_eq!(truncate_with(path, 0, &opts), None);
+    }
+
+    /// Test the `components.len() != length` early return.
+    ///
+    /// This test ensures that requesting more components than the path has
+    /// yields `None` rather than a partial result.
+    #[test]
+    fn test_truncate_with_length_exceeds_components() {
+        let path = Path::new("a/b");
+        let opts = TruncateOptions::default();
+        assert_eq!(truncate_with(path, 5, &opts), None);
+    }
+
+    /// Test that a trailing separator is appended for directories.
+    ///
+    /// This test uses the current directory, which is guaranteed to exist and
+    /// be a directory, so the separator should be appended.
+    #[test]
+    fn test_truncate_with_trailing_separator_on_dir() {
+        let path = Path::new(".");
+        let opts = TruncateOptions {
+            separator: Some("/".to_string()),
+            trailing_separator: true,
+        };
+        assert_eq!(
+            truncate_with(path, 1, &opts),
+            Some("./".to_string())
+        );
+    }
+
+    /// Test that `sanitize_child_name` replaces spaces with underscores.
+    ///
+    /// This test preserves the previous `move_output_directory` behaviour.
+    #[test]
+    fn test_sanitize_child_name_spaces() {
+        assert_eq!(
+            sanitize_child_name("My Website"),
+            Ok("My_Website".to_string())
+        );
+    }
+
+    /// Test that `sanitize_child_name` rejects `.` and `..` components.
+    ///
+    /// This test ensures traversal components are refused outright.
+    #[test]
+    fn test_sanitize_child_name_rejects_dots() {
+        assert!(sanitize_child_name(".").is_err());
+        assert!(sanitize_child_name("..").is_err());
+        assert!(sanitize_child_name("").is_err());
+    }
+
+    /// Test that `sanitize_child_name` rejects path separators.
+    ///
+    /// This test covers both the Unix and Windows separators.
+    #[test]
+    fn test_sanitize_child_name_rejects_separators() {
+        assert!(sanitize_child_name("a/b").is_err());
+        assert!(sanitize_child_name("a\\b").is_err());
+    }
+
+    /// Test the post-strip `..` re-check.
+    ///
+    /// The reserved `?` is stripped, leaving `..`, which must still be rejected
+    /// rather than slipping through as a valid name.
+    #[test]
+    fn test_sanitize_child_name_post_strip_dotdot() {
+        assert!(sanitize_child_name(".?.").is_err());
+    }
+
+    /// Test that `sanitize_child_name` strips reserved characters.
+    ///
+    /// This test checks that reserved characters are removed from an otherwise
+    /// valid name.
+    #[test]
+    fn test_sanitize_child_name_strips_reserved() {
+        assert_eq!(
+            sanitize_child_name("na<me>"),
+            Ok("name".to_string())
+        );
+    }
+
+    /// Test that `canonical_output_dir` rejects a traversal child.
+    ///
+    /// The separator in the child is caught by `sanitize_child_name` before
+    /// any filesystem access occurs.
+    #[test]
+    fn test_canonical_output_dir_rejects_traversal() {
+        assert!(
+            canonical_output_dir(Path::new("public"), "../etc")
+                .is_err()
+        );
+    }
+
+    /// Test that `AbsDir::new` rejects a path containing `..`.
+    ///
+    /// This guards against traversal before the path is canonicalized, so the
+    /// rejection happens without touching the filesystem.
+    #[test]
+    fn test_abs_dir_rejects_traversal() {
+        assert!(AbsDir::new("public/../etc").is_err());
+    }
+
+    /// Test that `AbsDir::new` resolves an existing directory to an absolute
+    /// path.
+    ///
+    /// The current directory always exists and is a directory.
+    #[test]
+    fn test_abs_dir_new_current_dir() {
+        let dir = AbsDir::new(".").expect("current dir exists");
+        assert!(dir.as_path().is_absolute());
+    }
+
+    /// Test that `AbsDir::join_child` validates its child component.
+    ///
+    /// A valid child is joined, while a traversal child is rejected.
+    #[test]
+    fn test_abs_dir_join_child() {
+        let dir = AbsDir::new(".").expect("current dir exists");
+
+        let joined =
+            dir.join_child("site").expect("site is valid");
+        assert!(joined.starts_with(dir.as_path()));
+
+        assert!(dir.join_child("../escape").is_err());
+    }
+}